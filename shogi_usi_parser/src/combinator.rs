@@ -0,0 +1,92 @@
+//! A minimal `nom`-style combinator core shared by this crate's parsers.
+//!
+//! Every primitive here has the same shape as a hand-written parser would:
+//! it takes a `&[u8]` and returns the unconsumed tail alongside the parsed
+//! value, or a [`crate::Error`] whose byte offsets are relative to the slice
+//! it was given. [`shift`] re-bases those offsets once a primitive's result
+//! is folded into a larger parser that started earlier in the original input.
+
+use alloc::vec::Vec;
+
+use crate::{Error, Result};
+
+/// Shifts the byte offsets carried by `e` by `offset`, for when `e` was produced by a
+/// parser that was handed a sub-slice starting `offset` bytes into the original input.
+pub(crate) fn shift(e: Error, offset: usize) -> Error {
+    match e {
+        Error::InvalidInput {
+            from,
+            to,
+            description,
+        } => Error::InvalidInput {
+            from: from + offset,
+            to: to + offset,
+            description,
+        },
+        Error::CountOverflow {
+            from,
+            to,
+            description,
+        } => Error::CountOverflow {
+            from: from + offset,
+            to: to + offset,
+            description,
+        },
+    }
+}
+
+/// Matches a single expected byte, consuming it on success.
+pub(crate) fn tag(expected: u8, description: &'static str) -> impl Fn(&[u8]) -> Result<(&[u8], u8)> {
+    move |s: &[u8]| match s.first() {
+        Some(&b) if b == expected => Ok((&s[1..], b)),
+        _ => Err(Error::InvalidInput {
+            from: 0,
+            to: usize::from(!s.is_empty()),
+            description,
+        }),
+    }
+}
+
+/// Consumes the longest prefix of `s` all of whose bytes satisfy `pred`.
+/// Always succeeds, possibly with an empty match.
+pub(crate) fn take_while<'a>(
+    pred: impl Fn(u8) -> bool,
+) -> impl Fn(&'a [u8]) -> Result<(&'a [u8], &'a [u8])> {
+    move |s: &'a [u8]| {
+        let n = s.iter().take_while(|&&b| pred(b)).count();
+        Ok((&s[n..], &s[..n]))
+    }
+}
+
+/// Transforms the value produced by `p` with `f`, leaving errors untouched.
+pub(crate) fn map<'a, T, U>(
+    p: impl Fn(&'a [u8]) -> Result<(&'a [u8], T)>,
+    f: impl Fn(T) -> U,
+) -> impl Fn(&'a [u8]) -> Result<(&'a [u8], U)> {
+    move |s: &'a [u8]| p(s).map(|(rest, value)| (rest, f(value)))
+}
+
+/// Tries `first`; if it fails, tries `second` on the same input instead.
+/// The error reported when both fail is `second`'s.
+pub(crate) fn alt<'a, T>(
+    first: impl Fn(&'a [u8]) -> Result<(&'a [u8], T)>,
+    second: impl Fn(&'a [u8]) -> Result<(&'a [u8], T)>,
+) -> impl Fn(&'a [u8]) -> Result<(&'a [u8], T)> {
+    move |s: &'a [u8]| first(s).or_else(|_| second(s))
+}
+
+/// Applies `p` one or more times, collecting the results.
+/// Fails with `p`'s error if `p` does not succeed even once.
+pub(crate) fn many1<'a, T>(
+    p: impl Fn(&'a [u8]) -> Result<(&'a [u8], T)>,
+) -> impl Fn(&'a [u8]) -> Result<(&'a [u8], Vec<T>)> {
+    move |s: &'a [u8]| {
+        let (mut s, first) = p(s)?;
+        let mut out = alloc::vec![first];
+        while let Ok((rest, value)) = p(s) {
+            out.push(value);
+            s = rest;
+        }
+        Ok((s, out))
+    }
+}
@@ -1,8 +1,108 @@
 use core::slice;
-use shogi_core::{Color, Hand, Piece};
+use shogi_core::{Color, Hand, Piece, PieceKind};
 
+use crate::combinator::{many1, shift, take_while};
 use crate::{Error, FromUsi, Result};
 
+/// The piece kinds that may appear in a USI hand field, in the canonical descending
+/// value order mandated by the original spec (`R, B, G, S, N, L, P`).
+const HAND_ORDER: [PieceKind; 7] = [
+    PieceKind::Rook,
+    PieceKind::Bishop,
+    PieceKind::Gold,
+    PieceKind::Silver,
+    PieceKind::Knight,
+    PieceKind::Lance,
+    PieceKind::Pawn,
+];
+
+/// The legal maximum count of each of [`HAND_ORDER`]'s piece kinds: two Rooks,
+/// two Bishops, four of each Gold/Silver/Knight/Lance, and eighteen Pawns.
+const HAND_MAX: [u32; 7] = [2, 2, 4, 4, 4, 4, 18];
+
+/// The position of `kind` in [`HAND_ORDER`] and its legal maximum count, or `None`
+/// for a kind that cannot be held in hand at all (the King, or any promoted piece).
+fn hand_rank_and_max(kind: PieceKind) -> Option<(usize, u32)> {
+    let rank = HAND_ORDER.iter().position(|&k| k == kind)?;
+    Some((rank, HAND_MAX[rank]))
+}
+
+/// A single hand entry (e.g. the `"4P"` in `"4P2b"`), together with how many bytes
+/// of input it consumed.
+struct HandEntry {
+    count: u32,
+    piece: Piece,
+    consumed: usize,
+}
+
+/// Parses the optional leading count of a hand entry (e.g. the `4` in `"4P"`),
+/// defaulting to `1` when no digits are present.
+fn parse_count(s: &[u8]) -> Result<(&[u8], u32)> {
+    let (rest, digits) = take_while(|b: u8| b.is_ascii_digit())(s)?;
+    if digits.is_empty() {
+        return Ok((rest, 1));
+    }
+    if digits.len() > 2 {
+        return Err(Error::InvalidInput {
+            from: 0,
+            to: digits.len(),
+            description: "A hand count must be at most 2 digits long",
+        });
+    }
+    let mut count = 0;
+    for &digit in digits {
+        count = 10 * count + u32::from(digit - b'0');
+    }
+    Ok((rest, count))
+}
+
+/// Parses one hand entry, an optional count followed by a single unpromoted piece letter.
+fn parse_entry(s: &[u8]) -> Result<(&[u8], HandEntry)> {
+    let (rest, count) = parse_count(s)?;
+    let count_len = s.len() - rest.len();
+    let (rest, piece) = Piece::parse_usi_slice(rest).map_err(|e| shift(e, count_len))?;
+    let consumed = s.len() - rest.len();
+    Ok((
+        rest,
+        HandEntry {
+            count,
+            piece,
+            consumed,
+        },
+    ))
+}
+
+/// Adds `entry` to `hand`, tracking the running per-(side, kind) count in `counts` and
+/// rejecting a count that would exceed that kind's legal maximum. Returns the kind's
+/// rank in [`HAND_ORDER`] so strict-mode parsing can additionally check ordering.
+fn add_entry(
+    hand: &mut [Hand; 2],
+    counts: &mut [[u32; 7]; 2],
+    entry: &HandEntry,
+    offset: usize,
+) -> Result<usize> {
+    let side = usize::from(entry.piece.color() == Color::White);
+    let (rank, max) = hand_rank_and_max(entry.piece.piece_kind()).ok_or(Error::InvalidInput {
+        from: offset,
+        to: offset + entry.consumed,
+        description: "This piece kind cannot be held in hand",
+    })?;
+    counts[side][rank] += entry.count;
+    if counts[side][rank] > max {
+        return Err(Error::CountOverflow {
+            from: offset,
+            to: offset + entry.consumed,
+            description: "This piece kind's count exceeds its legal maximum in hand",
+        });
+    }
+    for _ in 0..entry.count {
+        hand[side] = hand[side]
+            .added(entry.piece.piece_kind())
+            .expect("count was already checked against the legal maximum for this piece kind");
+    }
+    Ok(rank)
+}
+
 /// ```
 /// # use shogi_core::{Hand, PieceKind};
 /// use shogi_usi_parser::FromUsi;
@@ -28,6 +128,9 @@ use crate::{Error, FromUsi, Result};
 ///
 /// let hand = <[Hand; 2]>::from_usi_lite("-").unwrap();
 /// assert_eq!(hand[0].count(PieceKind::Silver), Some(0)); // black
+///
+/// // Exceeding the legal maximum for a piece kind is an error, not a silent clamp.
+/// assert!(<[Hand; 2]>::from_usi_lite("19p").is_none());
 /// ```
 impl FromUsi for [Hand; 2] {
     fn parse_usi_slice(s: &[u8]) -> Result<(&[u8], Self)> {
@@ -42,63 +145,85 @@ impl FromUsi for [Hand; 2] {
             // empty
             return Ok((&s[1..], [Hand::default(); 2]));
         }
-        // If there are some pieces in hand, each letter must represent a valid unpromoted piece or the number of same pieces.
         // Although [the standard](https://web.archive.org/web/20080131070731/http://www.glaurungchess.com/shogi/usi.html) defines the strict order of pieces,
         // this parser allows a slightly wider set of inputs: order doesn't matter, same pieces can appear multiple times.
-        let mut index = 0;
+        let (rest, entries) = many1(parse_entry)(s)?;
+
         let mut hand = [Hand::default(); 2];
-        while index < s.len() {
-            let mut count = 1;
-            let mut count_len = 0;
-            if matches!(s[index], b'0'..=b'9') {
-                // length of the number should be 1 or 2
-                let mut this = s[index] - b'0';
-                if index + 1 < s.len() && matches!(s[index + 1], b'0'..=b'9') {
-                    this = 10 * this + (s[index + 1] - b'0');
-                    count_len = 2;
-                } else {
-                    count_len = 1;
-                }
-                count = this;
-            }
-            let result = Piece::parse_usi_slice(&s[index + count_len..index + count_len + 1]);
-            let piece = if let Ok((_, piece)) = result {
-                piece
-            } else {
-                break;
-            };
-            let piece_kind = piece.piece_kind();
-            match piece.color() {
-                Color::Black => {
-                    for _ in 0..count {
-                        hand[0] = if let Some(newhand) = hand[0].added(piece_kind) {
-                            newhand
-                        } else {
-                            break;
-                        }
-                    }
-                }
-                Color::White => {
-                    for _ in 0..count {
-                        hand[1] = if let Some(newhand) = hand[1].added(piece_kind) {
-                            newhand
-                        } else {
-                            break;
-                        }
-                    }
-                }
-            }
-            index += count_len + 1;
+        let mut counts = [[0u32; 7]; 2];
+        let mut offset = 0;
+        for entry in &entries {
+            add_entry(&mut hand, &mut counts, entry, offset)?;
+            offset += entry.consumed;
         }
-        if index == 0 {
-            // Nothing was read. Since empty hand is represented as "-", this is irrational.
+        Ok((rest, hand))
+    }
+
+    /// ```
+    /// use shogi_core::Hand;
+    /// use shogi_usi_parser::FromUsi;
+    /// // The spec-mandated order (Black's R, B, G, S, N, L, P then White's) is accepted...
+    /// assert!(<[Hand; 2]>::from_usi_strict("RG4P2b2s3p").is_some());
+    /// // ...but an out-of-order encoding that the lenient parser tolerates is not.
+    /// assert!(<[Hand; 2]>::from_usi_strict("PNSP").is_none());
+    /// // Nor is a duplicated piece kind, a zero count, or White appearing before Black.
+    /// assert!(<[Hand; 2]>::from_usi_strict("PP").is_none());
+    /// assert!(<[Hand; 2]>::from_usi_strict("0P").is_none());
+    /// assert!(<[Hand; 2]>::from_usi_strict("pR").is_none());
+    /// ```
+    fn parse_usi_slice_strict(s: &[u8]) -> Result<(&[u8], Self)> {
+        if s.is_empty() {
             return Err(Error::InvalidInput {
                 from: 0,
-                to: 1,
-                description: "A `[Hand; 2]` expected, but no pieces were found",
+                to: 0,
+                description: "A `[Hand; 2]` expected, but nothing found",
             });
         }
-        Ok((&s[index..], hand))
+        if s[0] == b'-' {
+            return Ok((&s[1..], [Hand::default(); 2]));
+        }
+        let (rest, entries) = many1(parse_entry)(s)?;
+
+        let mut hand = [Hand::default(); 2];
+        let mut counts = [[0u32; 7]; 2];
+        // The highest-ranked piece kind seen so far for each side, `None` before the
+        // first entry of that side has been read.
+        let mut last_rank: [Option<usize>; 2] = [None, None];
+        let mut last_side = None;
+        let mut offset = 0;
+        for entry in &entries {
+            let side = usize::from(entry.piece.color() == Color::White);
+            if let Some(last_side) = last_side {
+                if side < last_side {
+                    return Err(Error::InvalidInput {
+                        from: offset,
+                        to: offset + entry.consumed,
+                        description: "Black's pieces must all precede White's in strict mode",
+                    });
+                }
+            }
+            last_side = Some(side);
+            if entry.count == 0 {
+                return Err(Error::InvalidInput {
+                    from: offset,
+                    to: offset + entry.consumed,
+                    description: "A hand count of 0 is not allowed in strict mode",
+                });
+            }
+            let rank = add_entry(&mut hand, &mut counts, entry, offset)?;
+            if let Some(last_rank) = last_rank[side] {
+                if rank <= last_rank {
+                    return Err(Error::InvalidInput {
+                        from: offset,
+                        to: offset + entry.consumed,
+                        description: "Piece kinds must appear in descending value order (R, B, G, S, N, L, P) and at most once in strict mode",
+                    });
+                }
+            }
+            last_rank[side] = Some(rank);
+            offset += entry.consumed;
+        }
+        Ok((rest, hand))
     }
 }
 
@@ -124,3 +249,27 @@ pub unsafe extern "C" fn Hand_parse_usi_slice(hand: &mut [Hand; 2], s: *const u8
         Err(_) => -1,
     }
 }
+
+/// C interface of `<[Hand; 2]>::parse_usi_slice_strict`, for embedders that need to
+/// validate untrusted GUI/engine traffic against the original spec.
+/// If parse error occurs, it returns -1.
+/// If parsing succeeds, it returns the number of read bytes.
+///
+/// # Safety
+/// `hand` must be a valid pointer to Hand[2].
+/// `s` must be a nul-terminated C string.
+#[no_mangle]
+pub unsafe extern "C" fn Hand_parse_usi_slice_strict(hand: &mut [Hand; 2], s: *const u8) -> isize {
+    let mut length = 0;
+    while *s.add(length) != 0 {
+        length += 1;
+    }
+    let slice = slice::from_raw_parts(s, length);
+    match <[Hand; 2]>::parse_usi_slice_strict(slice) {
+        Ok((slice, resulting_hand)) => {
+            *hand = resulting_hand;
+            slice.as_ptr().offset_from(s)
+        }
+        Err(_) => -1,
+    }
+}
@@ -0,0 +1,86 @@
+//! A parser for strings used in [USI (Universal Shogi Interface)](http://www.geocities.jp/shogidokoro/usi.html),
+//! the de-facto standard protocol for communication between shogi GUIs and engines.
+//!
+//! The entry point is the [`FromUsi`] trait, implemented for the pieces of state that
+//! appear in USI `position` commands: [`shogi_core::Piece`], `[shogi_core::Hand; 2]`,
+//! [`UsiMove`] and [`shogi_core::PartialPosition`]. Serializing back to USI strings
+//! is already covered by `shogi_core`'s own `ToUsi` trait and
+//! `PartialPosition::to_sfen_owned`; this crate only adds the parsing direction.
+
+#![no_std]
+
+extern crate alloc;
+
+mod combinator;
+mod hand;
+mod mv;
+mod piece;
+mod position;
+
+pub use mv::UsiMove;
+
+/// An error that occurs while parsing a USI string.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Error {
+    /// The input does not conform to the USI grammar.
+    ///
+    /// `from` and `to` are byte offsets into the original input that delimit the
+    /// offending span (`to` is exclusive); they may coincide if no specific span
+    /// can be singled out.
+    InvalidInput {
+        from: usize,
+        to: usize,
+        description: &'static str,
+    },
+    /// A count (e.g. the number of a piece kind in hand) exceeds the legal maximum.
+    ///
+    /// This is kept distinct from [`Error::InvalidInput`] because the input is
+    /// syntactically well-formed; it is only illegal given shogi's rules.
+    CountOverflow {
+        from: usize,
+        to: usize,
+        description: &'static str,
+    },
+}
+
+/// The result type used throughout this crate.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A type that can be parsed from (a part of) a USI string.
+pub trait FromUsi: Sized {
+    /// Parses `s` from the beginning, returning the unconsumed tail and the parsed value.
+    ///
+    /// Implementations should consume as little as possible: just enough to
+    /// unambiguously recognize one value, leaving the rest of `s` for the caller
+    /// (e.g. the next field of a `position` command) to parse.
+    fn parse_usi_slice(s: &[u8]) -> Result<(&[u8], Self)>;
+
+    /// A convenience wrapper around [`FromUsi::parse_usi_slice`] for when `s` is
+    /// known to contain exactly one value and nothing else.
+    ///
+    /// Returns `None` if parsing fails or if `s` is not fully consumed.
+    fn from_usi_lite(s: &str) -> Option<Self> {
+        match Self::parse_usi_slice(s.as_bytes()) {
+            Ok(([], value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Like [`FromUsi::parse_usi_slice`], but enforces every requirement of the
+    /// original USI spec instead of the wider set of inputs this crate tolerates
+    /// by default (e.g. out-of-order or repeated pieces in a hand encoding).
+    ///
+    /// The default implementation is plain lenient parsing; types that accept a
+    /// superset of the spec override this to reject the extra leniency.
+    fn parse_usi_slice_strict(s: &[u8]) -> Result<(&[u8], Self)> {
+        Self::parse_usi_slice(s)
+    }
+
+    /// The strict-mode counterpart of [`FromUsi::from_usi_lite`].
+    fn from_usi_strict(s: &str) -> Option<Self> {
+        match Self::parse_usi_slice_strict(s.as_bytes()) {
+            Ok(([], value)) => Some(value),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use core::slice;
+use shogi_core::{Color, Move, Piece, PieceKind, Square};
+
+use crate::combinator::{alt, shift};
+use crate::{Error, FromUsi, Result};
+
+/// A move parsed directly from a USI move string, before the side to move is known.
+///
+/// A USI move string never encodes color: a normal move only names squares, and a
+/// drop's piece letter is always written uppercase regardless of whose turn it is.
+/// This mirrors that restriction by carrying a bare [`PieceKind`] for drops rather
+/// than a full [`Piece`]; call [`UsiMove::into_move`] with the side to move once it
+/// is known (from the `position`'s side-to-move field, for instance) to obtain a
+/// real [`shogi_core::Move`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum UsiMove {
+    Normal {
+        from: Square,
+        to: Square,
+        promote: bool,
+    },
+    Drop {
+        piece_kind: PieceKind,
+        to: Square,
+    },
+}
+
+impl UsiMove {
+    /// Resolves this move into a [`shogi_core::Move`], given the side making it.
+    pub fn into_move(self, side_to_move: Color) -> Move {
+        match self {
+            UsiMove::Normal { from, to, promote } => Move::Normal { from, to, promote },
+            UsiMove::Drop { piece_kind, to } => Move::Drop {
+                piece: Piece::new(piece_kind, side_to_move),
+                to,
+            },
+        }
+    }
+}
+
+/// Parses a single USI square such as `"7g"` into a [`Square`].
+///
+/// A square is a file digit `1`-`9` followed by a rank letter `a`-`i`.
+fn parse_square(s: &[u8]) -> Result<(&[u8], Square)> {
+    if s.len() < 2 {
+        return Err(Error::InvalidInput {
+            from: 0,
+            to: s.len(),
+            description: "A square expected, but the input was too short",
+        });
+    }
+    if !matches!(s[0], b'1'..=b'9') {
+        return Err(Error::InvalidInput {
+            from: 0,
+            to: 1,
+            description: "A file digit `1`-`9` expected",
+        });
+    }
+    if !matches!(s[1], b'a'..=b'i') {
+        return Err(Error::InvalidInput {
+            from: 1,
+            to: 2,
+            description: "A rank letter `a`-`i` expected",
+        });
+    }
+    let file = s[0] - b'0';
+    let rank = s[1] - b'a' + 1;
+    match Square::new(file, rank) {
+        Some(square) => Ok((&s[2..], square)),
+        None => Err(Error::InvalidInput {
+            from: 0,
+            to: 2,
+            description: "The square is out of range",
+        }),
+    }
+}
+
+/// Parses the piece-kind letter of a USI drop, e.g. the `S` in `"S*5b"`.
+///
+/// Only uppercase, unpromoted piece letters (other than `K`) are legal here:
+/// USI move strings carry no color information, so the letter is always
+/// written uppercase regardless of whose turn it is.
+fn parse_drop_piece_kind(s: &[u8]) -> Result<(&[u8], PieceKind)> {
+    if s.is_empty() {
+        return Err(Error::InvalidInput {
+            from: 0,
+            to: 0,
+            description: "A piece letter expected, but nothing found",
+        });
+    }
+    let piece_kind = match s[0] {
+        b'P' => PieceKind::Pawn,
+        b'L' => PieceKind::Lance,
+        b'N' => PieceKind::Knight,
+        b'S' => PieceKind::Silver,
+        b'G' => PieceKind::Gold,
+        b'B' => PieceKind::Bishop,
+        b'R' => PieceKind::Rook,
+        _ => {
+            return Err(Error::InvalidInput {
+                from: 0,
+                to: 1,
+                description: "A droppable piece letter (P, L, N, S, G, B or R) expected",
+            })
+        }
+    };
+    Ok((&s[1..], piece_kind))
+}
+
+/// Parses a drop move such as `"S*5b"`.
+fn parse_drop(s: &[u8]) -> Result<(&[u8], UsiMove)> {
+    let (rest, piece_kind) = parse_drop_piece_kind(s)?;
+    let piece_len = s.len() - rest.len();
+    let rest = match rest.first() {
+        Some(b'*') => &rest[1..],
+        _ => {
+            return Err(Error::InvalidInput {
+                from: piece_len,
+                to: piece_len + usize::from(!rest.is_empty()),
+                description: "Expected '*' after a drop's piece letter",
+            })
+        }
+    };
+    let (rest, to) = parse_square(rest).map_err(|e| shift(e, piece_len + 1))?;
+    Ok((rest, UsiMove::Drop { piece_kind, to }))
+}
+
+/// Parses a normal move such as `"7g7f"` or `"8h2b+"`.
+fn parse_normal(s: &[u8]) -> Result<(&[u8], UsiMove)> {
+    let (rest, from) = parse_square(s)?;
+    let (rest, to) = parse_square(rest).map_err(|e| shift(e, 2))?;
+    let (rest, promote) = match rest.first() {
+        Some(b'+') => (&rest[1..], true),
+        _ => (rest, false),
+    };
+    Ok((rest, UsiMove::Normal { from, to, promote }))
+}
+
+/// ```
+/// use shogi_core::{Color, Piece, PieceKind, Square};
+/// use shogi_usi_parser::FromUsi;
+/// let mv = UsiMove::from_usi_lite("7g7f").unwrap();
+/// assert_eq!(
+///     mv,
+///     UsiMove::Normal {
+///         from: Square::new(7, 7).unwrap(),
+///         to: Square::new(7, 6).unwrap(),
+///         promote: false,
+///     }
+/// );
+///
+/// let mv = UsiMove::from_usi_lite("8h2b+").unwrap();
+/// assert_eq!(
+///     mv,
+///     UsiMove::Normal {
+///         from: Square::new(8, 8).unwrap(),
+///         to: Square::new(2, 2).unwrap(),
+///         promote: true,
+///     }
+/// );
+///
+/// // A drop carries a bare `PieceKind`: a USI move string never says whose turn it is.
+/// let mv = UsiMove::from_usi_lite("S*5b").unwrap();
+/// assert_eq!(
+///     mv,
+///     UsiMove::Drop {
+///         piece_kind: PieceKind::Silver,
+///         to: Square::new(5, 2).unwrap(),
+///     }
+/// );
+/// // The side to move resolves it into a real `shogi_core::Move`.
+/// assert_eq!(
+///     mv.into_move(Color::White),
+///     Move::Drop {
+///         piece: Piece::new(PieceKind::Silver, Color::White),
+///         to: Square::new(5, 2).unwrap(),
+///     }
+/// );
+/// # use shogi_core::Move;
+/// # use shogi_usi_parser::UsiMove;
+/// ```
+impl FromUsi for UsiMove {
+    fn parse_usi_slice(s: &[u8]) -> Result<(&[u8], Self)> {
+        alt(parse_drop, parse_normal)(s)
+    }
+}
+
+/// C interface of `UsiMove::parse_usi_slice`, resolved into a `shogi_core::Move` via
+/// `side_to_move` (a USI move string does not encode color itself).
+/// If parse error occurs, it returns -1.
+/// If parsing succeeds, it returns the number of read bytes.
+///
+/// # Safety
+/// `mv` must be a valid pointer to a `Move`.
+/// `s` must be a nul-terminated C string.
+#[no_mangle]
+pub unsafe extern "C" fn Move_parse_usi_slice(
+    mv: &mut Move,
+    side_to_move: Color,
+    s: *const u8,
+) -> isize {
+    let mut length = 0;
+    while *s.add(length) != 0 {
+        length += 1;
+    }
+    let slice = slice::from_raw_parts(s, length);
+    match UsiMove::parse_usi_slice(slice) {
+        Ok((slice, resulting_move)) => {
+            *mv = resulting_move.into_move(side_to_move);
+            slice.as_ptr().offset_from(s)
+        }
+        Err(_) => -1,
+    }
+}
@@ -0,0 +1,90 @@
+use shogi_core::{Color, Piece, PieceKind};
+
+use crate::combinator::{alt, map, shift, tag};
+use crate::{Error, FromUsi, Result};
+
+/// Parses the optional leading `'+'` promotion marker, defaulting to `false`.
+fn parse_promoted(s: &[u8]) -> Result<(&[u8], bool)> {
+    alt(map(tag(b'+', "a promotion marker"), |_| true), |s: &[u8]| {
+        Ok((s, false))
+    })(s)
+}
+
+/// Parses a single USI piece letter (ignoring any promotion marker) into the
+/// unpromoted `(Color, PieceKind)` it denotes.
+fn parse_letter(s: &[u8]) -> Result<(&[u8], (Color, PieceKind))> {
+    let letter = match s.first() {
+        Some(&letter) => letter,
+        None => {
+            return Err(Error::InvalidInput {
+                from: 0,
+                to: 0,
+                description: "A piece letter expected, but nothing found",
+            })
+        }
+    };
+    let piece_kind = match letter.to_ascii_uppercase() {
+        b'P' => PieceKind::Pawn,
+        b'L' => PieceKind::Lance,
+        b'N' => PieceKind::Knight,
+        b'S' => PieceKind::Silver,
+        b'G' => PieceKind::Gold,
+        b'B' => PieceKind::Bishop,
+        b'R' => PieceKind::Rook,
+        b'K' => PieceKind::King,
+        _ => {
+            return Err(Error::InvalidInput {
+                from: 0,
+                to: 1,
+                description: "An unrecognized piece letter was found",
+            })
+        }
+    };
+    let color = if letter.is_ascii_uppercase() {
+        Color::Black
+    } else {
+        Color::White
+    };
+    Ok((&s[1..], (color, piece_kind)))
+}
+
+/// ```
+/// use shogi_core::{Color, PieceKind};
+/// use shogi_usi_parser::FromUsi;
+/// let piece = Piece::from_usi_lite("P").unwrap();
+/// assert_eq!(piece.piece_kind(), PieceKind::Pawn);
+/// assert_eq!(piece.color(), Color::Black);
+///
+/// // A lowercase letter denotes a White piece.
+/// let piece = Piece::from_usi_lite("p").unwrap();
+/// assert_eq!(piece.color(), Color::White);
+///
+/// // A leading '+' denotes a promoted piece.
+/// let piece = Piece::from_usi_lite("+p").unwrap();
+/// assert_eq!(piece.piece_kind(), PieceKind::ProPawn);
+/// # use shogi_core::Piece;
+/// ```
+impl FromUsi for Piece {
+    fn parse_usi_slice(s: &[u8]) -> Result<(&[u8], Self)> {
+        let (s, promoted) = parse_promoted(s)?;
+        if promoted && s.is_empty() {
+            return Err(Error::InvalidInput {
+                from: 0,
+                to: 0,
+                description: "A `Piece` expected, but only a promotion marker was found",
+            });
+        }
+        let (s, (color, piece_kind)) =
+            parse_letter(s).map_err(|e| shift(e, usize::from(promoted)))?;
+        let piece_kind = if promoted {
+            piece_kind.promote().ok_or(Error::InvalidInput {
+                from: 0,
+                to: 1,
+                description: "This piece kind cannot be promoted",
+            })?
+        } else {
+            piece_kind
+        };
+        Ok((s, Piece::new(piece_kind, color)))
+    }
+}
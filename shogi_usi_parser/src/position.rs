@@ -0,0 +1,177 @@
+use shogi_core::{Color, Hand, PartialPosition, Piece, Square};
+
+use crate::combinator::{alt, map, shift, tag, take_while};
+use crate::{Error, FromUsi, Result};
+
+/// One token of the board field: either a run of `n` empty squares or a single piece.
+enum BoardToken {
+    Empty(u8),
+    Piece(Piece),
+}
+
+fn parse_empty_run(s: &[u8]) -> Result<(&[u8], BoardToken)> {
+    match s.first() {
+        Some(&b @ b'1'..=b'9') => Ok((&s[1..], BoardToken::Empty(b - b'0'))),
+        _ => Err(Error::InvalidInput {
+            from: 0,
+            to: usize::from(!s.is_empty()),
+            description: "An empty-square run digit (1-9) expected",
+        }),
+    }
+}
+
+fn parse_board_token(s: &[u8]) -> Result<(&[u8], BoardToken)> {
+    alt(parse_empty_run, map(Piece::parse_usi_slice, BoardToken::Piece))(s)
+}
+
+/// Parses the board field of an SFEN string, a sequence of 9 ranks (`a` through
+/// `i`, top to bottom) separated by `'/'`. Within each rank, tokens are read from
+/// file 9 down to file 1.
+fn parse_board<'a>(s: &'a [u8], pos: &mut PartialPosition) -> Result<&'a [u8]> {
+    let mut s = s;
+    let mut offset = 0;
+    for rank in 1..=9u8 {
+        let mut remaining_files: i32 = 9;
+        while remaining_files > 0 {
+            if s.is_empty() {
+                return Err(Error::InvalidInput {
+                    from: offset,
+                    to: offset,
+                    description: "The board ended before 9 ranks were read",
+                });
+            }
+            let (rest, token) = parse_board_token(s).map_err(|e| shift(e, offset))?;
+            let consumed = s.len() - rest.len();
+            match token {
+                BoardToken::Empty(n) => {
+                    let n = i32::from(n);
+                    if n > remaining_files {
+                        return Err(Error::InvalidInput {
+                            from: offset,
+                            to: offset + consumed,
+                            description: "A rank has more than 9 files",
+                        });
+                    }
+                    remaining_files -= n;
+                }
+                BoardToken::Piece(piece) => {
+                    // Squares within a rank are listed from file 9 down to file 1.
+                    let file = remaining_files as u8;
+                    let square = Square::new(file, rank).ok_or(Error::InvalidInput {
+                        from: offset,
+                        to: offset + consumed,
+                        description: "The square computed from the board field is out of range",
+                    })?;
+                    pos.piece_set(square, Some(piece));
+                    remaining_files -= 1;
+                }
+            }
+            s = rest;
+            offset += consumed;
+        }
+        if rank < 9 {
+            let (rest, _) = tag(b'/', "Expected '/' between ranks")(s).map_err(|e| shift(e, offset))?;
+            s = rest;
+            offset += 1;
+        }
+    }
+    Ok(s)
+}
+
+/// Shared implementation behind both [`FromUsi::parse_usi_slice`] and
+/// [`FromUsi::parse_usi_slice_strict`] for [`PartialPosition`]; `strict` picks which
+/// of those two modes the embedded hands field is parsed with.
+fn parse(s: &[u8], strict: bool) -> Result<(&[u8], PartialPosition)> {
+    let mut pos = PartialPosition::default();
+
+    let s = parse_board(s, &mut pos)?;
+    let (s, _) = tag(b' ', "Expected ' ' after the board field")(s)?;
+
+    let side = match s.first() {
+        Some(b'b') => Color::Black,
+        Some(b'w') => Color::White,
+        _ => {
+            return Err(Error::InvalidInput {
+                from: 0,
+                to: s.first().map_or(0, |_| 1),
+                description: "Expected 'b' or 'w' for the side to move",
+            })
+        }
+    };
+    pos.side_to_move_set(side);
+    let s = &s[1..];
+    let (s, _) = tag(b' ', "Expected ' ' after the side-to-move field")(s)?;
+
+    let (s, hands) = if strict {
+        <[Hand; 2]>::parse_usi_slice_strict(s)?
+    } else {
+        <[Hand; 2]>::parse_usi_slice(s)?
+    };
+    *pos.hand_of_a_player_mut(Color::Black) = hands[0];
+    *pos.hand_of_a_player_mut(Color::White) = hands[1];
+    let (s, _) = tag(b' ', "Expected ' ' after the hands field")(s)?;
+
+    if s.is_empty() || !matches!(s[0], b'1'..=b'9') {
+        return Err(Error::InvalidInput {
+            from: 0,
+            to: s.first().map_or(0, |_| 1),
+            description: "Expected a positive move number",
+        });
+    }
+    let (s, digits) = take_while(|b: u8| b.is_ascii_digit())(s)?;
+    let mut move_number: u32 = 0;
+    for &digit in digits {
+        move_number = move_number
+            .checked_mul(10)
+            .and_then(|n| n.checked_add(u32::from(digit - b'0')))
+            .ok_or(Error::InvalidInput {
+                from: 0,
+                to: digits.len(),
+                description: "The move number is too large",
+            })?;
+    }
+    let move_number = u16::try_from(move_number).map_err(|_| Error::InvalidInput {
+        from: 0,
+        to: digits.len(),
+        description: "The move number is too large",
+    })?;
+    if !pos.ply_set(move_number) {
+        return Err(Error::InvalidInput {
+            from: 0,
+            to: digits.len(),
+            description: "The move number is too large",
+        });
+    }
+
+    Ok((s, pos))
+}
+
+/// ```
+/// use shogi_core::{Color, PieceKind, Square};
+/// use shogi_usi_parser::FromUsi;
+/// let pos = PartialPosition::from_usi_lite(
+///     "lnsgkgsnl/1r5b1/ppppppppp/9/9/9/PPPPPPPPP/1B5R1/LNSGKGSNL b - 1",
+/// )
+/// .unwrap();
+/// assert_eq!(pos.side_to_move(), Color::Black);
+/// assert_eq!(pos.ply(), 1);
+/// assert_eq!(
+///     pos.piece_at(Square::new(5, 1).unwrap())
+///         .unwrap()
+///         .piece_kind(),
+///     PieceKind::King
+/// );
+/// # use shogi_core::PartialPosition;
+/// ```
+impl FromUsi for PartialPosition {
+    fn parse_usi_slice(s: &[u8]) -> Result<(&[u8], Self)> {
+        parse(s, false)
+    }
+
+    /// Parses an SFEN string the same way as [`FromUsi::parse_usi_slice`], except
+    /// that the hands field is validated with `<[Hand; 2]>::parse_usi_slice_strict`
+    /// rather than the default lenient parser.
+    fn parse_usi_slice_strict(s: &[u8]) -> Result<(&[u8], Self)> {
+        parse(s, true)
+    }
+}